@@ -1,10 +1,9 @@
 extern crate mal;
 
-use mal::driver::Driver;
+use mal::driver::main_with;
+use mal::repl::rep;
 use mal::DEFAULT_PROMPT;
 
 fn main() {
-    let prompt = DEFAULT_PROMPT.to_string();
-    let mut driver = Driver::new(prompt);
-    driver.run();
+    main_with(DEFAULT_PROMPT.to_string(), rep);
 }
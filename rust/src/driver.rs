@@ -1,13 +1,18 @@
 use readline::Reader;
-use repl::rep;
+
+pub type Rep = fn(String) -> String;
 
 pub struct Driver {
     reader: Reader,
+    rep: Rep,
 }
 
 impl Driver {
-    pub fn new(prompt: String) -> Driver {
-        Driver { reader: Reader::new(prompt) }
+    pub fn new(prompt: String, rep: Rep) -> Driver {
+        Driver {
+            reader: Reader::new(prompt),
+            rep,
+        }
     }
 
     pub fn run(&mut self) {
@@ -19,7 +24,7 @@ impl Driver {
             let input = self.reader.read();
             match input {
                 Some(line) => {
-                    let output = rep(line);
+                    let output = (self.rep)(line);
                     println!("{}", output);
                 }
                 None => break,
@@ -27,3 +32,8 @@ impl Driver {
         }
     }
 }
+
+pub fn main_with(prompt: String, rep: Rep) {
+    let mut driver = Driver::new(prompt, rep);
+    driver.run();
+}
@@ -1,6 +1,6 @@
 extern crate rustyline;
 
-pub const DEFAULT_PROMPT: &'static str = "user> ";
+pub const DEFAULT_PROMPT: &str = "user> ";
 
 pub mod driver;
 pub mod readline;
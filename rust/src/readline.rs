@@ -18,7 +18,7 @@ pub struct DefaultReader {
 
 impl DefaultReader {
     pub fn new(prompt: String) -> DefaultReader {
-        DefaultReader { prompt: prompt }
+        DefaultReader { prompt }
     }
 
     pub fn read(&mut self) -> Option<String> {
@@ -33,7 +33,7 @@ impl DefaultReader {
         match read {
             // catches CTRL-D
             Ok(n) => {
-                if n <= 0 {
+                if n == 0 {
                     None
                 } else {
                     Some(input.trim().to_string())
@@ -52,7 +52,7 @@ pub struct LineReader {
     history_path: String,
 }
 
-const HISTORY_FILENAME: &'static str = ".mal-history.txt";
+const HISTORY_FILENAME: &str = ".mal-history.txt";
 
 impl LineReader {
     pub fn new(prompt: String) -> LineReader {
@@ -61,8 +61,8 @@ impl LineReader {
         let path = LineReader::init_history(&mut editor, HISTORY_FILENAME).unwrap();
 
         LineReader {
-            prompt: prompt,
-            editor: editor,
+            prompt,
+            editor,
             history_path: path,
         }
     }
@@ -79,11 +79,11 @@ impl LineReader {
 
         editor.save_history(&path).unwrap();
         editor.load_history(&path)
-            .and_then(|_| {
-                Ok(path.as_path()
+            .map(|_| {
+                path.as_path()
                     .to_str()
                     .unwrap()
-                    .to_string())
+                    .to_string()
             })
     }
 
@@ -0,0 +1,32 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+fn run_with_input(bin: &str, input: &str) -> String {
+    let mut child = Command::new(bin)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("could not start binary");
+
+    child.stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .expect("could not write to stdin");
+
+    let output = child.wait_with_output().expect("could not read stdout");
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn main_echoes_input() {
+    let stdout = run_with_input(env!("CARGO_BIN_EXE_main"), "(1 2 3)\n");
+    assert_eq!(stdout, "user> (1 2 3)\nuser> ");
+}
+
+#[test]
+fn step0_repl_echoes_input() {
+    let stdout = run_with_input(env!("CARGO_BIN_EXE_step0_repl"), "(1 2 3)\n");
+    assert_eq!(stdout, "user> (1 2 3)\nuser> ");
+}